@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use zed_extension_api as zed;
+use zed_extension_api::http_client::{fetch, HttpMethod, HttpRequest};
+use zed_extension_api::KeyValueStore;
+
+/// Id this extension registers its docs provider under, referenced from
+/// `extension.toml`'s `[doc_providers]` table.
+pub const PROVIDER_ID: &str = "inkscape";
+
+struct DocPage {
+    key: &'static str,
+    url: &'static str,
+}
+
+/// `inkscape --help-all` options worth having grounded reference text for.
+const CLI_PAGES: &[DocPage] = &[
+    DocPage {
+        key: "--export-type",
+        url: "https://inkscape.org/doc/inkscape-man.html#export-type",
+    },
+    DocPage {
+        key: "--export-filename",
+        url: "https://inkscape.org/doc/inkscape-man.html#export-filename",
+    },
+    DocPage {
+        key: "--export-area-page",
+        url: "https://inkscape.org/doc/inkscape-man.html#export-area-page",
+    },
+    DocPage {
+        key: "--export-id",
+        url: "https://inkscape.org/doc/inkscape-man.html#export-id",
+    },
+];
+
+/// SVG elements/attributes that come up most often when editing paths and
+/// filters by hand.
+const ELEMENT_PAGES: &[DocPage] = &[
+    DocPage {
+        key: "path",
+        url: "https://www.w3.org/TR/SVG2/paths.html#PathData",
+    },
+    DocPage {
+        key: "viewBox",
+        url: "https://www.w3.org/TR/SVG2/coords.html#ViewBoxAttribute",
+    },
+    DocPage {
+        key: "filter",
+        url: "https://www.w3.org/TR/filter-effects-1/#FilterProperty",
+    },
+    DocPage {
+        key: "feGaussianBlur",
+        url: "https://www.w3.org/TR/filter-effects-1/#feGaussianBlurElement",
+    },
+];
+
+pub fn suggest_packages() -> zed::Result<Vec<String>> {
+    Ok(vec!["cli".to_string(), "elements".to_string()])
+}
+
+pub fn index(package: &str, database: &KeyValueStore) -> zed::Result<()> {
+    let pages = match package {
+        "cli" => CLI_PAGES,
+        "elements" => ELEMENT_PAGES,
+        other => return Err(format!("unknown inkscape docs package: {other}")),
+    };
+
+    // Several pages share one document and differ only by anchor fragment;
+    // fetch each distinct document once and slice out the relevant section
+    // per page instead of storing the whole page under every key.
+    let mut fetched_pages: HashMap<&str, String> = HashMap::new();
+
+    for page in pages {
+        let (base_url, fragment) = split_fragment(page.url);
+        let body = match fetched_pages.get(base_url) {
+            Some(body) => body.clone(),
+            None => {
+                let body = fetch_page(base_url)?;
+                fetched_pages.insert(base_url, body.clone());
+                body
+            }
+        };
+
+        let section = fragment
+            .and_then(|fragment| extract_section(&body, fragment))
+            .unwrap_or(body);
+        database.insert(&format!("{package}/{}", page.key), &section)?;
+    }
+
+    Ok(())
+}
+
+fn fetch_page(url: &str) -> zed::Result<String> {
+    let request = HttpRequest::builder()
+        .method(HttpMethod::Get)
+        .url(url)
+        .build()?;
+    let response = fetch(&request)?;
+    String::from_utf8(response.body).map_err(|err| err.to_string())
+}
+
+fn split_fragment(url: &str) -> (&str, Option<&str>) {
+    match url.split_once('#') {
+        Some((base, fragment)) => (base, Some(fragment)),
+        None => (url, None),
+    }
+}
+
+/// Pulls the plain text of a window of up to `SECTION_WINDOW` bytes that
+/// starts at the `id="<fragment>"` (or `name="<fragment>"`) attribute out of
+/// a full HTML page, so an indexed entry is a quotable snippet rather than
+/// the entire document. The window starts at the attribute text itself, not
+/// at the enclosing tag, and is not cut off at the end of the anchored element.
+fn extract_section(html: &str, fragment: &str) -> Option<String> {
+    const SECTION_WINDOW: usize = 4000;
+
+    let anchor = html
+        .find(&format!("id=\"{fragment}\""))
+        .or_else(|| html.find(&format!("name=\"{fragment}\"")))?;
+    let anchor = floor_char_boundary(html, anchor);
+    let window_end = floor_char_boundary(html, (anchor + SECTION_WINDOW).min(html.len()));
+
+    Some(strip_tags(&html[anchor..window_end]))
+}
+
+/// Rounds `index` down to the nearest UTF-8 char boundary, so it's always
+/// safe to slice a `&str` at. Plain byte-offset arithmetic (like
+/// `anchor + SECTION_WINDOW`) can otherwise land in the middle of a
+/// multi-byte character and panic on slicing.
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    while index > 0 && !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Strips HTML tags and collapses whitespace, leaving readable text.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_fragment_splits_on_hash() {
+        assert_eq!(
+            split_fragment("https://example.com/doc.html#export-type"),
+            ("https://example.com/doc.html", Some("export-type"))
+        );
+    }
+
+    #[test]
+    fn split_fragment_none_when_no_hash() {
+        assert_eq!(
+            split_fragment("https://example.com/doc.html"),
+            ("https://example.com/doc.html", None)
+        );
+    }
+
+    #[test]
+    fn strip_tags_removes_markup_and_collapses_whitespace() {
+        let html = "<p>Hello   <b>world</b>\n</p>";
+        assert_eq!(strip_tags(html), "Hello world");
+    }
+
+    #[test]
+    fn extract_section_finds_id_anchor() {
+        // extract_section starts at the `id="..."` attribute itself (not the
+        // enclosing tag), then strip_tags drops the markup from there —
+        // including the `>` that closes the opening tag, since stripping
+        // starts mid-tag.
+        let html = r#"<div id="export-type">Sets the export type.</div>"#;
+        assert_eq!(
+            extract_section(html, "export-type"),
+            Some(r#"id="export-type"Sets the export type."#.to_string())
+        );
+    }
+
+    #[test]
+    fn extract_section_finds_name_anchor() {
+        let html = r#"<a name="path">Path data</a>"#;
+        assert_eq!(
+            extract_section(html, "path"),
+            Some(r#"name="path"Path data"#.to_string())
+        );
+    }
+
+    #[test]
+    fn extract_section_none_when_fragment_missing() {
+        let html = r#"<div id="other">text</div>"#;
+        assert_eq!(extract_section(html, "missing"), None);
+    }
+
+    #[test]
+    fn extract_section_does_not_panic_when_window_splits_a_multibyte_char() {
+        // Pad so that `anchor + SECTION_WINDOW` lands in the middle of the
+        // multi-byte `é` (2 bytes in UTF-8), which previously panicked with
+        // "byte index N is not a char boundary". The window starts at the
+        // anchor marker, so the marker and the `>` after it count towards it,
+        // and one byte less of padding puts the window end on `é`'s 2nd byte.
+        const SECTION_WINDOW: usize = 4000;
+        let anchor_marker = r#"id="frag""#;
+        let padding = "a".repeat(SECTION_WINDOW - anchor_marker.len() - ">".len() - 1);
+        let html = format!("<div {anchor_marker}>{padding}é</div>");
+
+        let section = extract_section(&html, "frag");
+        assert!(section.is_some());
+    }
+
+    #[test]
+    fn floor_char_boundary_steps_back_to_previous_boundary() {
+        let s = "aé"; // 'a' is 1 byte, 'é' is 2 bytes starting at index 1
+        assert_eq!(floor_char_boundary(s, 2), 1);
+        assert_eq!(floor_char_boundary(s, 1), 1);
+        assert_eq!(floor_char_boundary(s, 0), 0);
+    }
+}
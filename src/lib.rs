@@ -1,20 +1,138 @@
+use serde::Deserialize;
 use zed_extension_api as zed;
+use zed_extension_api::settings::{CommandSettings, ContextServerSettings};
 
-struct InkscapeMcpExtension;
+mod docs_provider;
+mod provisioning;
+mod slash_commands;
+
+/// Shape of the `context_servers.inkscape-mcp.settings` block in the user's
+/// Zed settings, for the bits that aren't already covered by `command`.
+#[derive(Debug, Default, Deserialize)]
+struct InkscapeMcpSettings {
+    /// Path to an `inkscape` binary that isn't on `PATH`, forwarded to the
+    /// server as `INKSCAPE_PATH`.
+    inkscape_path: Option<String>,
+}
+
+#[derive(Default)]
+struct InkscapeMcpExtension {
+    cached_binary_path: Option<String>,
+}
+
+/// Builds a [`zed::Command`] from the user's `context_servers.inkscape-mcp.command`
+/// settings, if they specified a binary path. `arguments`/`env` are optional
+/// on top of that and default to empty.
+fn command_from_settings(settings: CommandSettings) -> Option<zed::Command> {
+    let command = settings.path?;
+    Some(zed::Command {
+        command,
+        args: settings.arguments.unwrap_or_default(),
+        env: settings
+            .env
+            .map(|env| env.into_iter().collect())
+            .unwrap_or_default(),
+    })
+}
+
+impl InkscapeMcpExtension {
+    /// The command Zed falls back to when the user hasn't configured one
+    /// for this context server in their settings and no provisioned binary
+    /// could be found or downloaded.
+    fn default_command() -> zed::Command {
+        zed::Command {
+            command: "uv".to_string(),
+            args: vec!["run".to_string(), "inkscape-mcp".to_string()],
+            env: Default::default(),
+        }
+    }
+}
 
 impl zed::Extension for InkscapeMcpExtension {
+    fn new() -> Self {
+        Self::default()
+    }
+
     fn context_server_command(
         &mut self,
         id: &zed::ContextServerId,
-        _project: &zed::Project,
+        project: &zed::Project,
     ) -> zed::Result<zed::Command> {
-        match id.0.as_str() {
-            "inkscape-mcp" => Ok(zed::Command {
-                command: "uv".to_string(),
-                args: vec!["run".to_string(), "inkscape-mcp".to_string()],
-                env: Default::default(),
-            }),
-            _ => Err(format!("Unknown server: {}", id.0)),
+        match id.as_ref() {
+            "inkscape-mcp" => {
+                let mut settings = InkscapeMcpSettings::default();
+                let mut command_override = None;
+
+                if let Ok(context_server_settings) =
+                    ContextServerSettings::for_project(id.as_ref(), project)
+                {
+                    command_override = context_server_settings.command;
+
+                    if let Some(raw_settings) = context_server_settings.settings {
+                        settings = serde_json::from_value(raw_settings).unwrap_or_default();
+                    }
+                }
+
+                // Only fall back to provisioning/`uv run` when the user hasn't
+                // pointed us at a binary of their own.
+                let mut command = match command_override.and_then(command_from_settings) {
+                    Some(command) => command,
+                    None => match provisioning::ensure_server_binary(&mut self.cached_binary_path)
+                    {
+                        Some(binary_path) => zed::Command {
+                            command: binary_path,
+                            args: Vec::new(),
+                            env: Default::default(),
+                        },
+                        None => Self::default_command(),
+                    },
+                };
+
+                if let Some(inkscape_path) = settings.inkscape_path {
+                    command
+                        .env
+                        .push(("INKSCAPE_PATH".to_string(), inkscape_path));
+                }
+
+                Ok(command)
+            }
+            _ => Err(format!("Unknown server: {}", id.as_ref())),
+        }
+    }
+
+    fn complete_slash_command_argument(
+        &self,
+        command: zed::SlashCommand,
+        args: Vec<String>,
+    ) -> zed::Result<Vec<zed::SlashCommandArgumentCompletion>> {
+        slash_commands::complete_argument(&command, &args)
+    }
+
+    fn run_slash_command(
+        &self,
+        command: zed::SlashCommand,
+        args: Vec<String>,
+        worktree: Option<&zed::Worktree>,
+    ) -> zed::Result<zed::SlashCommandOutput> {
+        slash_commands::run(&command, &args, worktree)
+    }
+
+    fn suggest_docs_packages(&self, provider: String) -> zed::Result<Vec<String>> {
+        match provider.as_str() {
+            docs_provider::PROVIDER_ID => docs_provider::suggest_packages(),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    fn index_docs(
+        &self,
+        provider: String,
+        package: String,
+        database: &zed::KeyValueStore,
+    ) -> zed::Result<()> {
+        match provider.as_str() {
+            docs_provider::PROVIDER_ID => docs_provider::index(&package, database),
+            _ => Err(format!("unknown docs provider: {provider}")),
         }
     }
 }
@@ -0,0 +1,98 @@
+use zed_extension_api as zed;
+
+/// Repository that publishes prebuilt `inkscape-mcp` server binaries, for
+/// users who don't already have one on `PATH` via `uv`/pipx.
+const RELEASE_REPO: &str = "sandraschi/inkscape-mcp-server";
+
+/// Downloads (or reuses a previously downloaded) standalone `inkscape-mcp`
+/// server binary, caching the result for subsequent launches. Returns `None`
+/// (never an error) when no binary can be provisioned for this platform, so
+/// callers can fall back to the `uv run inkscape-mcp` default rather than
+/// failing to start the server at all.
+///
+/// `context_server_command` only has access to a `Project`, not a
+/// `Worktree`, so there's no way to check `$PATH` here the way language
+/// server provisioning does — this only ever looks at our own cache and the
+/// GitHub release.
+pub fn ensure_server_binary(cached_binary_path: &mut Option<String>) -> Option<String> {
+    if let Some(path) = cached_binary_path.as_ref() {
+        if std::fs::metadata(path).is_ok() {
+            return Some(path.clone());
+        }
+    }
+
+    match download_binary() {
+        Ok(binary_path) => {
+            *cached_binary_path = Some(binary_path.clone());
+            Some(binary_path)
+        }
+        Err(_) => None,
+    }
+}
+
+fn download_binary() -> zed::Result<String> {
+    let release = zed::latest_github_release(
+        RELEASE_REPO,
+        zed::GithubReleaseOptions {
+            require_assets: true,
+            pre_release: false,
+        },
+    )?;
+
+    let (platform, arch) = zed::current_platform();
+    let asset_name = asset_name_for(platform, arch)?;
+    let asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name == asset_name)
+        .ok_or_else(|| {
+            format!(
+                "inkscape-mcp release {} has no asset named {asset_name} for this platform",
+                release.version
+            )
+        })?;
+
+    let version_dir = format!("inkscape-mcp-{}", release.version);
+    let binary_path = format!("{version_dir}/{}", binary_name_for(platform));
+
+    if std::fs::metadata(&binary_path).is_err() {
+        let file_kind = if platform == zed::Os::Windows {
+            zed::DownloadedFileType::Zip
+        } else {
+            zed::DownloadedFileType::GzipTar
+        };
+        zed::download_file(&asset.download_url, &version_dir, file_kind)?;
+        zed::make_file_executable(&binary_path)?;
+    }
+
+    Ok(binary_path)
+}
+
+fn binary_name_for(platform: zed::Os) -> &'static str {
+    match platform {
+        zed::Os::Windows => "inkscape-mcp.exe",
+        zed::Os::Mac | zed::Os::Linux => "inkscape-mcp",
+    }
+}
+
+fn asset_name_for(platform: zed::Os, arch: zed::Architecture) -> zed::Result<String> {
+    let os = match platform {
+        zed::Os::Mac => "apple-darwin",
+        zed::Os::Linux => "unknown-linux-gnu",
+        zed::Os::Windows => "pc-windows-msvc",
+    };
+    let arch = match arch {
+        zed::Architecture::Aarch64 => "aarch64",
+        zed::Architecture::X8664 => "x86_64",
+        zed::Architecture::X86 => {
+            return Err("inkscape-mcp has no prebuilt release for 32-bit x86".to_string())
+        }
+    };
+    let extension = if platform == zed::Os::Windows {
+        "zip"
+    } else {
+        "tar.gz"
+    };
+
+    Ok(format!("inkscape-mcp-{arch}-{os}.{extension}"))
+}
@@ -0,0 +1,262 @@
+use std::fmt::Write as _;
+use zed_extension_api as zed;
+use zed_extension_api::{
+    SlashCommand, SlashCommandArgumentCompletion, SlashCommandOutput, SlashCommandOutputSection,
+    Worktree,
+};
+
+pub const SVG_EXPORT: &str = "svg-export";
+pub const SVG_OPTIMIZE: &str = "svg-optimize";
+pub const SVG_QUERY: &str = "svg-query";
+
+const EXPORT_FORMATS: &[&str] = &["png", "pdf"];
+
+/// Zed calls this without a `Worktree`, so argument completions can only be
+/// derived from the arguments already typed — there's no way to read the
+/// referenced SVG file to suggest its object IDs here. `/svg-query` falls
+/// back to accepting whatever the user types; only `/svg-export`'s format
+/// argument gets real completions.
+pub fn complete_argument(
+    command: &SlashCommand,
+    args: &[String],
+) -> zed::Result<Vec<SlashCommandArgumentCompletion>> {
+    match (command.name.as_str(), args) {
+        (SVG_EXPORT, [_path, partial_format]) => Ok(EXPORT_FORMATS
+            .iter()
+            .filter(|format| format.starts_with(partial_format.as_str()))
+            .map(|format| completion(format))
+            .collect()),
+        _ => Ok(Vec::new()),
+    }
+}
+
+pub fn run(
+    command: &SlashCommand,
+    args: &[String],
+    worktree: Option<&Worktree>,
+) -> zed::Result<SlashCommandOutput> {
+    match command.name.as_str() {
+        SVG_EXPORT => run_export(args, worktree),
+        SVG_OPTIMIZE => run_optimize(args, worktree),
+        SVG_QUERY => run_query(args, worktree),
+        other => Err(format!("Unknown slash command: {other}")),
+    }
+}
+
+fn run_export(args: &[String], worktree: Option<&Worktree>) -> zed::Result<SlashCommandOutput> {
+    let [path, format] = args else {
+        return Err("usage: /svg-export <file> <png|pdf>".to_string());
+    };
+    if !EXPORT_FORMATS.contains(&format.as_str()) {
+        return Err(format!(
+            "unsupported export format '{format}' (expected one of {EXPORT_FORMATS:?})"
+        ));
+    }
+
+    let worktree = worktree.ok_or_else(|| "svg-export requires an open worktree".to_string())?;
+    let svg = worktree.read_text_file(path)?;
+    let dimensions = svg_dimensions(&svg).unwrap_or_else(|| "unknown".to_string());
+    let output_path = with_extension(path, format);
+
+    // This slash command only previews the export; the extension has no way
+    // to run Inkscape itself, so the actual conversion happens through the
+    // inkscape-mcp context server.
+    let mut text = String::new();
+    writeln!(text, "Will export {path} to {output_path} via inkscape-mcp").unwrap();
+    let path_section_end = text.len() as u32;
+    writeln!(text, "Current dimensions: {dimensions}").unwrap();
+
+    Ok(SlashCommandOutput {
+        text,
+        sections: vec![SlashCommandOutputSection {
+            range: (0..path_section_end).into(),
+            label: output_path,
+        }],
+    })
+}
+
+fn run_optimize(args: &[String], worktree: Option<&Worktree>) -> zed::Result<SlashCommandOutput> {
+    let [path] = args else {
+        return Err("usage: /svg-optimize <file>".to_string());
+    };
+
+    let worktree = worktree.ok_or_else(|| "svg-optimize requires an open worktree".to_string())?;
+    let svg = worktree.read_text_file(path)?;
+    let object_count = object_ids_in(worktree, path)?.len();
+
+    // As with /svg-export, this only previews what optimizing would touch;
+    // the actual pass runs through the inkscape-mcp context server.
+    let mut text = String::new();
+    writeln!(
+        text,
+        "Will optimize {path} via inkscape-mcp ({} bytes, {object_count} objects currently)",
+        svg.len()
+    )
+    .unwrap();
+    let label_end = text.len() as u32;
+
+    Ok(SlashCommandOutput {
+        text,
+        sections: vec![SlashCommandOutputSection {
+            range: (0..label_end).into(),
+            label: path.clone(),
+        }],
+    })
+}
+
+fn run_query(args: &[String], worktree: Option<&Worktree>) -> zed::Result<SlashCommandOutput> {
+    let [path, object_id] = args else {
+        return Err("usage: /svg-query <file> <object-id>".to_string());
+    };
+
+    let worktree = worktree.ok_or_else(|| "svg-query requires an open worktree".to_string())?;
+    let svg = worktree.read_text_file(path)?;
+    if !object_ids_in(worktree, path)?.iter().any(|id| id == object_id) {
+        return Err(format!("no object with id '{object_id}' in {path}"));
+    }
+
+    let mut text = String::new();
+    writeln!(text, "Object '{object_id}' in {path}").unwrap();
+    let label_end = text.len() as u32;
+    writeln!(text, "{}", element_snippet(&svg, object_id).unwrap_or_default()).unwrap();
+
+    Ok(SlashCommandOutput {
+        text,
+        sections: vec![SlashCommandOutputSection {
+            range: (0..label_end).into(),
+            label: object_id.clone(),
+        }],
+    })
+}
+
+fn completion(value: &str) -> SlashCommandArgumentCompletion {
+    SlashCommandArgumentCompletion {
+        label: value.to_string(),
+        new_text: value.to_string(),
+        run_command: true,
+    }
+}
+
+/// Scans an SVG document for `id="..."` attributes, in document order.
+fn object_ids_in(worktree: &Worktree, path: &str) -> zed::Result<Vec<String>> {
+    let svg = worktree.read_text_file(path)?;
+    Ok(attribute_values(&svg, "id="))
+}
+
+/// Pulls the `width`/`height` (falling back to `viewBox`) out of the `<svg>`
+/// root element so we can show the caller what they're about to export.
+fn svg_dimensions(svg: &str) -> Option<String> {
+    let root_start = svg.find("<svg")?;
+    let root = match svg[root_start..].find('>') {
+        Some(end) => &svg[root_start..root_start + end],
+        None => &svg[root_start..],
+    };
+    let width = attribute_values(root, "width=").into_iter().next();
+    let height = attribute_values(root, "height=").into_iter().next();
+    if let (Some(width), Some(height)) = (width, height) {
+        return Some(format!("{width}x{height}"));
+    }
+    attribute_values(root, "viewBox=").into_iter().next()
+}
+
+fn element_snippet(svg: &str, object_id: &str) -> Option<String> {
+    let needle = format!("id=\"{object_id}\"");
+    let attr_start = svg.find(&needle)?;
+    let tag_start = svg[..attr_start].rfind('<')?;
+    let tag_end = svg[tag_start..].find('>').map(|end| tag_start + end + 1)?;
+    Some(svg[tag_start..tag_end].to_string())
+}
+
+/// Returns the values of every `name"value"` occurrence (e.g. `id="foo"`),
+/// in the order they appear.
+fn attribute_values(svg: &str, name: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut rest = svg;
+    while let Some(start) = rest.find(name) {
+        rest = &rest[start + name.len()..];
+        let Some(quote) = rest.strip_prefix('"').or_else(|| rest.strip_prefix('\'')) else {
+            continue;
+        };
+        if let Some(end) = quote.find(['"', '\'']) {
+            values.push(quote[..end].to_string());
+            rest = &quote[end + 1..];
+        }
+    }
+    values
+}
+
+fn with_extension(path: &str, extension: &str) -> String {
+    match path.rfind('.') {
+        Some(dot) => format!("{}.{extension}", &path[..dot]),
+        None => format!("{path}.{extension}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribute_values_finds_quoted_values_in_order() {
+        let svg = r#"<svg><rect id="a"/><circle id='b'/></svg>"#;
+        assert_eq!(attribute_values(svg, "id="), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn attribute_values_ignores_unquoted_attributes() {
+        let svg = r#"<rect id=unquoted/><circle id="quoted"/>"#;
+        assert_eq!(attribute_values(svg, "id="), vec!["quoted"]);
+    }
+
+    #[test]
+    fn attribute_values_does_not_match_inside_other_attribute_values() {
+        // The literal text "id=" appears inside `title`'s value here, but it
+        // isn't followed by a quote there, so it must not be picked up as an
+        // `id=` attribute.
+        let svg = r#"<rect title="see id=legacy" id="real"/>"#;
+        assert_eq!(attribute_values(svg, "id="), vec!["real"]);
+    }
+
+    #[test]
+    fn svg_dimensions_prefers_width_and_height() {
+        let svg = r#"<svg width="100" height="50" viewBox="0 0 200 100">"#;
+        assert_eq!(svg_dimensions(svg), Some("100x50".to_string()));
+    }
+
+    #[test]
+    fn svg_dimensions_falls_back_to_view_box() {
+        let svg = r#"<svg viewBox="0 0 200 100">"#;
+        assert_eq!(svg_dimensions(svg), Some("0 0 200 100".to_string()));
+    }
+
+    #[test]
+    fn svg_dimensions_none_when_nothing_present() {
+        let svg = "<svg>";
+        assert_eq!(svg_dimensions(svg), None);
+    }
+
+    #[test]
+    fn element_snippet_returns_self_closing_tag() {
+        let svg = r#"<svg><path id="foo" d="M0 0"/></svg>"#;
+        assert_eq!(
+            element_snippet(svg, "foo"),
+            Some(r#"<path id="foo" d="M0 0"/>"#.to_string())
+        );
+    }
+
+    #[test]
+    fn element_snippet_none_when_id_missing() {
+        let svg = r#"<svg><path id="foo"/></svg>"#;
+        assert_eq!(element_snippet(svg, "missing"), None);
+    }
+
+    #[test]
+    fn with_extension_replaces_existing_suffix() {
+        assert_eq!(with_extension("drawing.svg", "png"), "drawing.png");
+    }
+
+    #[test]
+    fn with_extension_appends_when_no_suffix() {
+        assert_eq!(with_extension("drawing", "pdf"), "drawing.pdf");
+    }
+}